@@ -111,6 +111,30 @@ impl<T: Buf> Iterator for Iter<T> {
         let rem = self.inner.remaining();
         (rem, Some(rem))
     }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+        where F: FnMut(B, u8) -> B,
+    {
+        let mut acc = init;
+
+        // Walk the buffer one contiguous chunk at a time instead of going
+        // through `next`, which checks `has_remaining` for every byte.
+        while self.inner.has_remaining() {
+            let cnt = {
+                let bytes = self.inner.bytes();
+
+                for &b in bytes {
+                    acc = f(acc, b);
+                }
+
+                bytes.len()
+            };
+
+            self.inner.advance(cnt);
+        }
+
+        acc
+    }
 }
 
 impl<T: Buf> ExactSizeIterator for Iter<T> { }
@@ -20,3 +20,28 @@ fn empty_iter_len() {
     assert_eq!(iter.size_hint(), (0, Some(0)));
     assert_eq!(iter.len(), 0);
 }
+
+#[test]
+fn iter_fold() {
+    for len in &[0, 1, 7, 64, 1000] {
+        let data: Vec<u8> = (0..*len).map(|i| i as u8).collect();
+
+        let buf = Bytes::from(&data[..]).into_buf();
+        let sum = buf.iter().fold(0u64, |acc, b| acc + b as u64);
+
+        assert_eq!(sum, data.iter().map(|&b| b as u64).sum::<u64>());
+    }
+}
+
+#[test]
+fn iter_fold_across_chunks() {
+    let a = Bytes::from(&b"hello "[..]).into_buf();
+    let b = Bytes::from(&b"world"[..]).into_buf();
+
+    let collected = a.chain(b).iter().fold(Vec::new(), |mut acc, b| {
+        acc.push(b);
+        acc
+    });
+
+    assert_eq!(collected, b"hello world");
+}